@@ -0,0 +1,161 @@
+//! Extractor-based typed handlers.
+//!
+//! Most `Handler`s spend their first few lines digging values back out of the `Request` — decoding
+//! a JSON body, parsing the query string, cloning shared state, reading a route parameter — before
+//! they get to the interesting part. `FromRequest` moves that digging into reusable types, so a
+//! handler can declare what it needs in its signature:
+//!
+//! ```ignore
+//! fn add(Json(params): Json<AddParams>) -> IronResult<Response> { ... }
+//! ```
+//!
+//! A function pointer whose arguments all implement `FromRequest` and whose `Ok` type is
+//! `Into<Response>` is itself a `Handler`, via the impls below — one per arity, mirroring the bare
+//! `fn(&mut Request) -> IronResult<Response>` impl in `middleware`. The generated `call` runs each
+//! extractor in declaration order and returns the first extractor error through the normal
+//! `catch`/`AfterMiddleware` path, exactly as a hand-written `Handler` would.
+
+use std::collections::TreeMap;
+
+use serialize::json;
+use serialize::Decodable;
+
+use url::form_urlencoded;
+
+use error::Error;
+use super::{Request, Response, IronResult, Handler};
+
+/// TypeMap key holding the raw, matched route parameters as a string for `Path` to decode.
+pub struct Params(pub String);
+
+impl Params {
+    fn as_slice(&self) -> &str {
+        let Params(ref raw) = *self;
+        raw.as_slice()
+    }
+}
+
+/// Error returned when a `State<T>` extractor finds no value of type `T` in the extensions.
+pub struct MissingState;
+
+impl Error for MissingState {
+    fn name(&self) -> &str { "Missing state" }
+}
+
+/// Error returned when a `Path<T>` extractor runs without any matched route parameters.
+pub struct MissingParams;
+
+impl Error for MissingParams {
+    fn name(&self) -> &str { "Missing route parameters" }
+}
+
+/// Lets a `json::DecoderError` travel the crate's error path, so the extractors below can surface a
+/// failed decode as an ordinary `IronResult` error.
+impl Error for json::DecoderError {
+    fn name(&self) -> &str { "JSON decoding error" }
+}
+
+/// Decode an `application/x-www-form-urlencoded` string into `T`.
+///
+/// This is the encoding `Query` and `Path` expect: the pairs are parsed into a flat object of
+/// string-valued fields, which is then decoded into `T`. A value that `T` cannot accept (for
+/// instance a numeric field) surfaces as the decoder's error through the usual path.
+fn decode_form<T>(raw: &str) -> IronResult<T>
+where T: Decodable<json::Decoder, json::DecoderError> {
+    let mut object = TreeMap::new();
+    for (key, value) in form_urlencoded::parse(raw.as_bytes()).move_iter() {
+        object.insert(key, json::String(value));
+    }
+
+    match json::decode(json::Object(object).to_string().as_slice()) {
+        Ok(value) => Ok(value),
+        Err(err) => Err(box err as Box<Error + Send>)
+    }
+}
+
+/// Types that can be pulled out of a `Request` to form a handler argument.
+///
+/// An error here is returned from the generated `Handler::call` before the user function runs, so
+/// it travels the same `catch`/`AfterMiddleware` route as any other handler error.
+pub trait FromRequest {
+    /// Extract `Self` from the `Request`, or fail with an `IronResult` error.
+    fn from_request(&mut Request) -> IronResult<Self>;
+}
+
+/// A handler argument deserialized from the request's JSON body.
+pub struct Json<T>(pub T);
+
+impl<T: Decodable<json::Decoder, json::DecoderError>> FromRequest for Json<T> {
+    fn from_request(req: &mut Request) -> IronResult<Json<T>> {
+        match json::decode(req.body.as_slice()) {
+            Ok(value) => Ok(Json(value)),
+            Err(err) => Err(box err as Box<Error + Send>)
+        }
+    }
+}
+
+/// A handler argument deserialized from the URL query string.
+pub struct Query<T>(pub T);
+
+impl<T: Decodable<json::Decoder, json::DecoderError>> FromRequest for Query<T> {
+    fn from_request(req: &mut Request) -> IronResult<Query<T>> {
+        let query = req.url.query.clone().unwrap_or_else(|| String::new());
+        match decode_form(query.as_slice()) {
+            Ok(value) => Ok(Query(value)),
+            Err(err) => Err(err)
+        }
+    }
+}
+
+/// A handler argument cloned from shared application state held in the `Request`'s extensions.
+pub struct State<T>(pub T);
+
+impl<T: Clone + 'static> FromRequest for State<T> {
+    fn from_request(req: &mut Request) -> IronResult<State<T>> {
+        match req.extensions.find::<T>() {
+            Some(value) => Ok(State(value.clone())),
+            None => Err(box MissingState as Box<Error + Send>)
+        }
+    }
+}
+
+/// A handler argument deserialized from the matched route parameters.
+pub struct Path<T>(pub T);
+
+impl<T: Decodable<json::Decoder, json::DecoderError> + 'static> FromRequest for Path<T> {
+    fn from_request(req: &mut Request) -> IronResult<Path<T>> {
+        match req.extensions.find::<Params>() {
+            Some(params) => match decode_form(params.as_slice()) {
+                Ok(value) => Ok(Path(value)),
+                Err(err) => Err(err)
+            },
+            None => Err(box MissingParams as Box<Error + Send>)
+        }
+    }
+}
+
+macro_rules! from_request_handler {
+    ($($ty:ident),+) => (
+        impl<R $(, $ty)+> Handler for fn($($ty),+) -> IronResult<R>
+        where R: Into<Response>,
+              $($ty: FromRequest),+ {
+            fn call(&self, req: &mut Request) -> IronResult<Response> {
+                $(
+                    let $ty = match FromRequest::from_request(req) {
+                        Ok(arg) => arg,
+                        Err(err) => return Err(err)
+                    };
+                )+
+                match (*self)($($ty),+) {
+                    Ok(res) => Ok(res.into()),
+                    Err(err) => Err(err)
+                }
+            }
+        }
+    )
+}
+
+from_request_handler!(A);
+from_request_handler!(A, B);
+from_request_handler!(A, B, C);
+from_request_handler!(A, B, C, D);
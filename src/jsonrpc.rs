@@ -0,0 +1,211 @@
+//! A JSON-RPC 2.0 dispatch `Handler`.
+//!
+//! `JsonRpc` turns a table of ordinary Rust functions into a single `Handler` that speaks the
+//! JSON-RPC 2.0 protocol. Each registered function takes its deserialized `params` and returns a
+//! `Result<T, RpcError>`; the handler parses the request envelope, routes `method` to the matching
+//! function, and serializes either a `result` or a spec-compliant `error` object back into the
+//! `Response`, echoing the request's `id`:
+//!
+//! ```ignore
+//! fn add(p: (int, int)) -> Result<int, RpcError> { Ok(p.0 + p.1) }
+//!
+//! let rpc = JsonRpc::new().method("add", add).method("sub", sub);
+//! ```
+//!
+//! Because it is just a `Handler`, a `JsonRpc` slots into a `ChainBuilder` like any other and
+//! inherits its Before/After middleware for auth and logging. Batch requests (a top-level array)
+//! are dispatched element by element and their responses collected back into an array, and
+//! notifications (requests with no `id`) are dispatched but produce no response.
+
+use std::collections::{HashMap, TreeMap};
+use std::io;
+
+use serialize::json;
+use serialize::{Decodable, Encodable};
+
+use super::{Request, Response, IronResult, Handler, status};
+
+/// A spec-compliant JSON-RPC error, returned by a method to signal failure to the caller.
+///
+/// The `code`/`message`/`data` triple is serialized verbatim into the response's `error` member.
+/// The private constructors (`parse_error`, `invalid_request`, `method_not_found`,
+/// `invalid_params`) cover the protocol-level codes from the JSON-RPC 2.0 spec; method
+/// authors build their own with `RpcError::new` for application errors.
+pub struct RpcError {
+    /// The JSON-RPC error code. Codes from -32768 to -32000 are reserved by the spec.
+    pub code: i32,
+
+    /// A short, human-readable description of the error.
+    pub message: String,
+
+    /// Optional additional information about the error.
+    pub data: Option<json::Json>
+}
+
+impl RpcError {
+    /// Build an application error with the given code and message and no `data`.
+    pub fn new(code: i32, message: &str) -> RpcError {
+        RpcError { code: code, message: message.to_string(), data: None }
+    }
+
+    fn parse_error() -> RpcError { RpcError::new(-32700, "Parse error") }
+    fn invalid_request() -> RpcError { RpcError::new(-32600, "Invalid Request") }
+    fn method_not_found() -> RpcError { RpcError::new(-32601, "Method not found") }
+    fn invalid_params() -> RpcError { RpcError::new(-32602, "Invalid params") }
+}
+
+/// A registered method, with its concrete `params`/result types erased behind `json::Json`.
+type Method = Box<Fn(json::Json) -> Result<json::Json, RpcError> + Send + Sync>;
+
+/// A registry of named methods that dispatches a JSON-RPC 2.0 request body as a `Handler`.
+pub struct JsonRpc {
+    methods: HashMap<String, Method>
+}
+
+impl JsonRpc {
+    /// Construct an empty `JsonRpc` with no registered methods.
+    pub fn new() -> JsonRpc {
+        JsonRpc { methods: HashMap::new() }
+    }
+
+    /// Register `f` under `name`, consuming and returning `self` so calls can be chained.
+    ///
+    /// `f`'s argument is decoded from the request's `params` and its `Ok` value is serialized into
+    /// the response's `result`; a returned `RpcError` becomes the response's `error`. A `params`
+    /// value that fails to decode into `P` surfaces as a -32602 "Invalid params" error.
+    pub fn method<P, T, F>(mut self, name: &str, f: F) -> JsonRpc
+    where P: Decodable<json::Decoder, json::DecoderError>,
+          T: Encodable<json::Encoder<'static>, io::IoError>,
+          F: Fn(P) -> Result<T, RpcError> + Send + Sync + 'static {
+        let erased = box move |params: json::Json| -> Result<json::Json, RpcError> {
+            let decoded = match json::decode(params.to_string().as_slice()) {
+                Ok(value) => value,
+                Err(_) => return Err(RpcError::invalid_params())
+            };
+
+            match f(decoded) {
+                Ok(value) => Ok(json::from_str(json::encode(&value).as_slice()).unwrap()),
+                Err(err) => Err(err)
+            }
+        };
+
+        self.methods.insert(name.to_string(), erased as Method);
+        self
+    }
+
+    /// Dispatch a single request object, returning its response or `None` for a notification.
+    fn dispatch(&self, request: &json::Json) -> Option<json::Json> {
+        // A batch element that isn't even an object can't carry an `id`, so it is answered with an
+        // `id: null` error rather than silently treated as a notification.
+        if request.as_object().is_none() {
+            return Some(failure(json::Null, RpcError::invalid_request()));
+        }
+
+        let id = match request.find("id") {
+            Some(id) => id.clone(),
+            None => json::Null
+        };
+        let notification = request.find("id").is_none();
+
+        match request.find("jsonrpc").and_then(|v| v.as_string()) {
+            Some("2.0") => (),
+            _ => return reply(notification, failure(id, RpcError::invalid_request()))
+        }
+
+        let method = match request.find("method").and_then(|v| v.as_string()) {
+            Some(method) => method,
+            None => return reply(notification, failure(id, RpcError::invalid_request()))
+        };
+
+        let params = match request.find("params") {
+            Some(params) => params.clone(),
+            None => json::Null
+        };
+
+        match self.methods.find_equiv(&method) {
+            Some(f) => match (*f)(params) {
+                Ok(result) => reply(notification, success(id, result)),
+                Err(err) => reply(notification, failure(id, err))
+            },
+            None => reply(notification, failure(id, RpcError::method_not_found()))
+        }
+    }
+}
+
+impl Handler for JsonRpc {
+    fn call(&self, req: &mut Request) -> IronResult<Response> {
+        let request = match json::from_str(req.body.as_slice()) {
+            Ok(request) => request,
+            Err(_) => return Ok(json_response(json::encode(&failure(json::Null, RpcError::parse_error()))))
+        };
+
+        let response = match request.as_list() {
+            Some(batch) => {
+                // An empty batch is itself an invalid request per the spec.
+                if batch.is_empty() {
+                    failure(json::Null, RpcError::invalid_request())
+                } else {
+                    let mut responses = Vec::new();
+                    for element in batch.iter() {
+                        match self.dispatch(element) {
+                            Some(response) => responses.push(response),
+                            None => ()
+                        }
+                    }
+
+                    // A batch made up entirely of notifications draws no response at all.
+                    if responses.is_empty() {
+                        return Ok(Response::status(status::NoContent));
+                    }
+
+                    json::List(responses)
+                }
+            },
+
+            None => match self.dispatch(&request) {
+                Some(response) => response,
+                None => return Ok(Response::status(status::NoContent))
+            }
+        };
+
+        Ok(json_response(json::encode(&response)))
+    }
+}
+
+/// Suppress the response for a notification, otherwise hand it back to the caller.
+fn reply(notification: bool, response: json::Json) -> Option<json::Json> {
+    if notification { None } else { Some(response) }
+}
+
+/// Build a successful response envelope, echoing the request `id`.
+fn success(id: json::Json, result: json::Json) -> json::Json {
+    let mut object = TreeMap::new();
+    object.insert("jsonrpc".to_string(), json::String("2.0".to_string()));
+    object.insert("result".to_string(), result);
+    object.insert("id".to_string(), id);
+    json::Object(object)
+}
+
+/// Build an error response envelope, echoing the request `id`.
+fn failure(id: json::Json, err: RpcError) -> json::Json {
+    let mut error = TreeMap::new();
+    error.insert("code".to_string(), json::I64(err.code as i64));
+    error.insert("message".to_string(), json::String(err.message));
+    match err.data {
+        Some(data) => { error.insert("data".to_string(), data); },
+        None => ()
+    }
+
+    let mut object = TreeMap::new();
+    object.insert("jsonrpc".to_string(), json::String("2.0".to_string()));
+    object.insert("error".to_string(), json::Object(error));
+    object.insert("id".to_string(), id);
+    json::Object(object)
+}
+
+/// Wrap a serialized JSON-RPC body in a `200 OK` `Response`.
+fn json_response(body: String) -> Response {
+    let mut res = Response::status(status::Ok);
+    res.body = body;
+    res
+}
@@ -89,6 +89,19 @@ pub trait Handler: Send + Sync {
     }
 }
 
+/// The outcome of a `BeforeMiddleware`, letting it end the Chain early without raising an error.
+///
+/// This separates "I produced the final answer early" (`Halt`) from "something went wrong" (`Err`),
+/// so a short-circuiting before no longer has to travel the whole error-propagation path through
+/// every subsequent `catch`.
+pub enum BeforeAction {
+    /// Continue the Chain with the next `BeforeMiddleware`, or the `Handler`.
+    Continue,
+
+    /// Skip the remaining befores and the `Handler`, feeding this `Response` straight to the afters.
+    Halt(Response)
+}
+
 /// `BeforeMiddleware` are fired before a `Handler` is called inside of a Chain.
 ///
 /// `BeforeMiddleware` are responsible for doing request pre-processing that requires
@@ -100,11 +113,16 @@ pub trait Handler: Send + Sync {
 pub trait BeforeMiddleware: Send + Sync {
     /// Do whatever work this middleware should do with a `Request` object.
     ///
+    /// Returning `Ok(Continue)` lets the Chain proceed to the next `BeforeMiddleware` and, eventually,
+    /// the `Handler`. Returning `Ok(Halt(res))` means this middleware has produced the final answer
+    /// early (an auth guard returning 401, a cache hit, a redirect): the remaining befores and the
+    /// `Handler` are skipped and `res` is handed straight to the `AfterMiddleware` with no error.
+    ///
     /// An error here is propagated by the containing Chain to, first, this Middleware's
     /// `catch` method, then every subsequent `BeforeMiddleware`'s `catch` methods until one returns
     /// Ok(()) or the Chain's `Handler` is reached, at which point the `Handler`'s `catch` method is
     /// called to produce an error Response.
-    fn before(&self, &mut Request) -> IronResult<()>;
+    fn before(&self, &mut Request) -> IronResult<BeforeAction>;
 
     /// Try to `catch` an error thrown by this Middleware or a previous `BeforeMiddleware`.
     ///
@@ -143,6 +161,52 @@ pub trait AfterMiddleware: Send + Sync {
     }
 }
 
+/// The outcome threaded through the after chain: a `Response`, or an error paired with the
+/// `Response` that was current when it was raised.
+///
+/// Keeping the `Response` alongside the error on the `Err` branch means that the one a `Handler`'s
+/// `catch` built survives all the way to the `AfterMiddleware`, so existing after-middleware that
+/// inspect or modify a handler's error `Response` see it rather than a blank 500.
+pub type AfterResult = Result<Response, (Response, Box<Error + Send>)>;
+
+/// `After` is a single-method alternative to the `AfterMiddleware` after/catch split.
+///
+/// Instead of receiving success in `after` and failure in `catch`, an `After` middleware is handed
+/// the whole handler outcome as one `AfterResult`, so a logger or metrics middleware sees the status
+/// code on success *and* the error (together with its `Response`) on failure in one place. Returning
+/// `Ok` from an `Err` input downgrades the error into a normal `Response`, exactly like `catch`
+/// returning `Ok(())`.
+pub trait After: Send + Sync {
+    /// Post-process the handler outcome, receiving the result produced by the previous `After`.
+    fn after(&self, &mut Request, AfterResult) -> AfterResult;
+}
+
+/// Adapts a two-method `AfterMiddleware` into the single-method `After` trait.
+///
+/// An `Ok` outcome is routed to `after` and an `Err` outcome to `catch`, preserving the control-flow
+/// semantics existing `AfterMiddleware` rely on; an error raised by `after` is first offered to the
+/// same middleware's `catch`. The error `Response` carried by the outcome is the
+/// one `catch` works with, so the `Handler`'s error `Response` is threaded through unchanged.
+pub struct AfterAdapter<A>(pub A);
+
+impl<A> After for AfterAdapter<A> where A: AfterMiddleware {
+    fn after(&self, req: &mut Request, result: AfterResult) -> AfterResult {
+        match result {
+            Ok(mut res) => match self.0.after(req, &mut res) {
+                Ok(()) => Ok(res),
+                Err(err) => match self.0.catch(req, &mut res, err) {
+                    Ok(()) => Ok(res),
+                    Err(err) => Err((res, err))
+                }
+            },
+            Err((mut res, err)) => match self.0.catch(req, &mut res, err) {
+                Ok(()) => Ok(res),
+                Err(err) => Err((res, err))
+            }
+        }
+    }
+}
+
 /// AroundMiddleware are used to wrap and replace the `Handler` in a Chain.
 ///
 /// AroundMiddleware must themselves be `Handler`s, and can integrate an existing
@@ -159,6 +223,124 @@ pub trait AroundMiddleware: Handler {
     fn with_handler(&mut self, handler: Box<Handler + Send + Sync>);
 }
 
+/// `Middleware` is a unified alternative to the `BeforeMiddleware`/`AfterMiddleware` split.
+///
+/// A single `Middleware` sees both the inbound `Request` and the outbound `Response`, so logic
+/// that needs to straddle the `Handler` — timing, transactions, tracing spans — can keep its
+/// state in one object instead of coordinating across a Before and an After half.
+///
+/// A `Middleware` does its inbound work, calls `chain.next(req)` at the point it wants downstream
+/// processing to run, and may then inspect or modify the returned `Response` (or the `Err`) before
+/// returning. Not calling `next` short-circuits the chain entirely.
+pub trait Middleware: Send + Sync {
+    /// Handle a `Request`, delegating downstream processing to `chain.next(req)`.
+    fn handle(&self, &mut Request, chain: &MiddlewareChain) -> IronResult<Response>;
+}
+
+/// A cursor over the remaining `Middleware` in a Chain, terminating in the `Handler`.
+///
+/// Each call to `next` advances the cursor by one, so the recursion is linear and each
+/// `Middleware` is re-entered exactly once.
+pub trait MiddlewareChain {
+    /// Run the next `Middleware` in the chain, or the `Handler` if none remain.
+    fn next(&self, &mut Request) -> IronResult<Response>;
+}
+
+/// The `MiddlewareChain` cursor used by `ChainBuilder::link_around` middleware.
+///
+/// It borrows the slice of middleware still to run along with the terminal `Handler`; `next`
+/// peels the head off the slice and hands the tail to a fresh cursor.
+struct Cursor<'a> {
+    remaining: &'a [Box<Middleware + Send + Sync>],
+    handler: &'a Box<Handler + Send + Sync>
+}
+
+impl<'a> Cursor<'a> {
+    /// Run the remaining middleware and the terminal `Handler`, keeping the error `Response`.
+    ///
+    /// At the terminal, a `Handler` error is routed through its own `catch`, so the error path
+    /// carries the real `Response` the `Handler` built rather than a fabricated one. Middleware
+    /// speak `IronResult<Response>`, so an error raised by one carries no `Response` of its own.
+    fn run(&self, req: &mut Request) -> AfterResult {
+        if self.remaining.is_empty() {
+            match self.handler.call(req) {
+                Ok(res) => Ok(res),
+                Err(err) => match self.handler.catch(req, err) {
+                    (res, Ok(())) => Ok(res),
+                    (res, Err(err)) => Err((res, err))
+                }
+            }
+        } else {
+            let head = &self.remaining[0];
+            let cursor = Cursor {
+                remaining: self.remaining.slice_from(1),
+                handler: self.handler
+            };
+            match head.handle(req, &cursor) {
+                Ok(res) => Ok(res),
+                Err(err) => Err((Response::status(status::InternalServerError), err))
+            }
+        }
+    }
+}
+
+impl<'a> MiddlewareChain for Cursor<'a> {
+    fn next(&self, req: &mut Request) -> IronResult<Response> {
+        match self.run(req) {
+            Ok(res) => Ok(res),
+            Err((_, err)) => Err(err)
+        }
+    }
+}
+
+/// Adapts a `BeforeMiddleware` into the unified `Middleware` trait.
+///
+/// The wrapped middleware's `before` runs on the way in; on `Continue`, downstream processing
+/// continues via `chain.next`, on `Halt(res)` downstream is skipped and `res` is returned, and an
+/// error is propagated unchanged.
+pub struct AroundBefore<B>(pub B);
+
+impl<B> Middleware for AroundBefore<B> where B: BeforeMiddleware {
+    fn handle(&self, req: &mut Request, chain: &MiddlewareChain) -> IronResult<Response> {
+        match self.0.before(req) {
+            Ok(BeforeAction::Continue) => chain.next(req),
+            Ok(BeforeAction::Halt(res)) => Ok(res),
+            Err(err) => Err(err)
+        }
+    }
+}
+
+/// Adapts an `AfterMiddleware` into the unified `Middleware` trait.
+///
+/// Downstream processing runs first via `chain.next`; on success the wrapped middleware's `after`
+/// is given a chance to post-process the `Response`, and an error it raises goes to its own
+/// `catch`. A `Handler` error that the `Handler`'s `catch` handled arrives as that `Response` and
+/// goes to `after`. Any other error from downstream reaches `catch` with a fresh 500 `Response`:
+/// `chain.next` returns no `Response` alongside an error, so the one a `Handler`'s `catch` built
+/// is not passed on here.
+pub struct AroundAfter<A>(pub A);
+
+impl<A> Middleware for AroundAfter<A> where A: AfterMiddleware {
+    fn handle(&self, req: &mut Request, chain: &MiddlewareChain) -> IronResult<Response> {
+        match chain.next(req) {
+            Ok(mut res) => match self.0.after(req, &mut res) {
+                Ok(()) => Ok(res),
+                Err(err) => match self.0.catch(req, &mut res, err) {
+                    Ok(()) => Ok(res),
+                    Err(err) => Err(err)
+                }
+            },
+            Err(err) => {
+                let mut res = Response::status(status::InternalServerError);
+                match self.0.catch(req, &mut res, err) {
+                    Ok(()) => Ok(res),
+                    Err(err) => Err(err)
+                }
+            }
+        }
+    }
+}
+
 /// Chain's hold `BeforeMiddleware`, a `Handler`, and `AfterMiddleware` and are responsible
 /// for correctly dispatching a `Request` through them.
 ///
@@ -194,7 +376,8 @@ pub trait Chain: Handler {
 /// frameworks.
 pub struct ChainBuilder {
     befores: Vec<Box<BeforeMiddleware + Send + Sync>>,
-    afters: Vec<Box<AfterMiddleware + Send + Sync>>,
+    afters: Vec<Box<After + Send + Sync>>,
+    arounds: Vec<Box<Middleware + Send + Sync>>,
     handler: Box<Handler + Send + Sync>
 }
 
@@ -204,9 +387,86 @@ impl ChainBuilder {
         ChainBuilder {
             befores: vec![],
             afters: vec![],
+            arounds: vec![],
             handler: box handler as Box<Handler + Send + Sync>
         }
     }
+
+    /// Link a unified `Middleware` to the Chain.
+    ///
+    /// Unlike `link_before`/`link_after`, a `Middleware` added here sees both the `Request` and
+    /// the `Response` and decides for itself when downstream processing runs by calling
+    /// `chain.next`. Middleware run in registration order, wrapping the `Handler` like an onion.
+    pub fn link_around<M: Middleware>(&mut self, middleware: M) {
+        self.arounds.push(box middleware as Box<Middleware + Send + Sync>);
+    }
+
+    /// Link an existing `BeforeMiddleware` into the around `Middleware` pipeline.
+    ///
+    /// The before is wrapped in an `AroundBefore` adapter so it coexists with native `Middleware`,
+    /// running its `before` on the way in and delegating the rest of the chain to `chain.next`.
+    pub fn link_before_around<B: BeforeMiddleware>(&mut self, before: B) {
+        self.arounds.push(box AroundBefore(before) as Box<Middleware + Send + Sync>);
+    }
+
+    /// Link an existing `AfterMiddleware` into the around `Middleware` pipeline.
+    ///
+    /// The after is wrapped in an `AroundAfter` adapter so it coexists with native `Middleware`,
+    /// post-processing the `Response` (or catching the error) once `chain.next` returns.
+    pub fn link_after_around<A: AfterMiddleware>(&mut self, after: A) {
+        self.arounds.push(box AroundAfter(after) as Box<Middleware + Send + Sync>);
+    }
+
+    /// Link a single-method `After` to the Chain.
+    ///
+    /// Unlike `link_after`, which takes the two-method `AfterMiddleware` and adapts it, this
+    /// registers an `After` directly so the middleware sees the whole handler outcome as one
+    /// `AfterResult`. `After`s run after all adapted `AfterMiddleware` registered so far.
+    pub fn link_around_after<A: After>(&mut self, after: A) {
+        self.afters.push(box after as Box<After + Send + Sync>);
+    }
+
+    /// Mount a sub-`ChainBuilder` so it inherits this Chain's middleware.
+    ///
+    /// This is the way to hang a sub-application under a prefix and have the parent's auth/logging
+    /// apply automatically, without re-entering the parent's error machinery once per nesting level.
+    /// Rather than nesting `Handler`s, the sub-chain is *flattened* into this one: the child's
+    /// `befores` are spliced in after the parent's (so the parent's run first), the child's `afters`
+    /// are spliced in before the parent's (so the child's run first, closest to the `Handler`), and
+    /// the child's `Handler` becomes the terminal. `run_befores`/`run_afters` then operate over the
+    /// merged sequence in a single pass, so error-propagation order is well-defined across levels.
+    pub fn mount(&mut self, sub: ChainBuilder) {
+        use std::mem;
+
+        let ChainBuilder { befores, afters, arounds, handler } = sub;
+
+        for before in befores.move_iter() {
+            self.befores.push(before);
+        }
+
+        let parent_afters = mem::replace(&mut self.afters, afters);
+        for after in parent_afters.move_iter() {
+            self.afters.push(after);
+        }
+
+        for around in arounds.move_iter() {
+            self.arounds.push(around);
+        }
+
+        self.handler = handler;
+    }
+
+    /// Invoke the around `Middleware` chain, terminating in the `Handler`.
+    ///
+    /// The result carries the `Handler`'s `catch` `Response` on the error path, so the after chain
+    /// sees the real error `Response` rather than a fabricated one.
+    fn call_handler(&self, req: &mut Request) -> AfterResult {
+        let cursor = Cursor {
+            remaining: self.arounds.as_slice(),
+            handler: &self.handler
+        };
+        cursor.run(req)
+    }
 }
 
 impl Chain for ChainBuilder {
@@ -214,6 +474,7 @@ impl Chain for ChainBuilder {
         ChainBuilder {
             befores: vec![],
             afters: vec![],
+            arounds: vec![],
             handler: box handler as Box<Handler + Send + Sync>
         }
     }
@@ -222,7 +483,7 @@ impl Chain for ChainBuilder {
     where A: AfterMiddleware, B: BeforeMiddleware {
         let (before, after) = link;
         self.befores.push(box before as Box<BeforeMiddleware + Send + Sync>);
-        self.afters.push(box after as Box<AfterMiddleware + Send + Sync>);
+        self.afters.push(box AfterAdapter(after) as Box<After + Send + Sync>);
     }
 
     fn link_before<B>(&mut self, before: B) where B: BeforeMiddleware {
@@ -230,7 +491,7 @@ impl Chain for ChainBuilder {
     }
 
     fn link_after<A>(&mut self, after: A) where A: AfterMiddleware {
-        self.afters.push(box after as Box<AfterMiddleware + Send + Sync>);
+        self.afters.push(box AfterAdapter(after) as Box<After + Send + Sync>);
     }
 
     fn around<A>(&mut self, mut around: A) where A: AroundMiddleware {
@@ -246,29 +507,25 @@ impl Handler for ChainBuilder {
     fn call(&self, req: &mut Request) -> IronResult<Response> {
         let before_result = helpers::run_befores(req, self.befores.as_slice(), None);
 
-        let (res, err) = match before_result {
-            Ok(()) => match self.handler.call(req) {
-                Ok(res) => (res, None),
-                Err(e) => helpers::run_handler_catch(req, e, &self.handler)
-            },
+        let result = match before_result {
+            Ok(BeforeAction::Halt(res)) => Ok(res),
+            Ok(BeforeAction::Continue) => self.call_handler(req),
             Err(e) => helpers::run_handler_catch(req, e, &self.handler)
         };
 
-        helpers::run_afters(req, res, err, self.afters.as_slice())
+        helpers::run_afters(req, result, self.afters.as_slice())
     }
 
     fn catch(&self, req: &mut Request, err: Box<Error + Send>) -> (Response, IronResult<()>) {
         let before_result = helpers::run_befores(req, self.befores.as_slice(), Some(err));
 
-        let (res, err) = match before_result {
-            Ok(()) => match self.handler.call(req) {
-                Ok(res) => (res, None),
-                Err(e) => helpers::run_handler_catch(req, e, &self.handler)
-            },
+        let result = match before_result {
+            Ok(BeforeAction::Halt(res)) => Ok(res),
+            Ok(BeforeAction::Continue) => self.call_handler(req),
             Err(e) => helpers::run_handler_catch(req, e, &self.handler)
         };
 
-        match helpers::run_afters(req, res, err, self.afters.as_slice()) {
+        match helpers::run_afters(req, result, self.afters.as_slice()) {
             Ok(res) => (res, Ok(())),
             Err(err) => (Response::status(status::InternalServerError), Err(err))
         }
@@ -319,10 +576,10 @@ impl Handler for Arc<Box<Handler + Send + Sync>> {
 
 mod helpers {
     use super::super::{Request, Response, IronResult};
-    use super::{AfterMiddleware, BeforeMiddleware, Handler};
+    use super::{After, AfterResult, BeforeAction, BeforeMiddleware, Handler};
     use error::Error;
 
-    pub fn run_befores(req: &mut Request, befores: &[Box<BeforeMiddleware>], err: Option<Box<Error + Send>>) -> IronResult<()> {
+    pub fn run_befores(req: &mut Request, befores: &[Box<BeforeMiddleware>], err: Option<Box<Error + Send>>) -> IronResult<BeforeAction> {
         match err {
             Some(mut e) => {
                 for (i, before) in befores.iter().enumerate() {
@@ -337,45 +594,33 @@ mod helpers {
             None => {
                 for (i, before) in befores.iter().enumerate() {
                     match before.before(req) {
-                        Ok(_) => (),
+                        Ok(BeforeAction::Continue) => (),
+                        Ok(BeforeAction::Halt(res)) => return Ok(BeforeAction::Halt(res)),
                         Err(err) => return run_befores(req, befores.slice_from(i), Some(err))
                     }
                 }
-                Ok(())
+                Ok(BeforeAction::Continue)
             }
         }
     }
 
-    pub fn run_afters(req: &mut Request, mut res: Response, err: Option<Box<Error + Send>>,
-                  afters: &[Box<AfterMiddleware>]) -> IronResult<Response> {
-        match err {
-            Some(mut e) => {
-                for (i, after) in afters.iter().enumerate() {
-                    match after.catch(req, &mut res, e) {
-                        Ok(_) => return run_afters(req, res, None, afters),
-                        Err(new) => e = new
-                    }
-                }
-                Err(e)
-            },
-
-            None => {
-                for (i, after) in afters.iter().enumerate() {
-                    match after.after(req, &mut res) {
-                        Ok(_) => (),
-                        Err(err) => return run_afters(req, res, Some(err), afters.slice_from(i))
-                    }
-                }
-                Ok(res)
-            }
+    pub fn run_afters(req: &mut Request, result: AfterResult,
+                  afters: &[Box<After>]) -> IronResult<Response> {
+        let mut result = result;
+        for after in afters.iter() {
+            result = after.after(req, result);
+        }
+        match result {
+            Ok(res) => Ok(res),
+            Err((_, err)) => Err(err)
         }
     }
 
     pub fn run_handler_catch(req: &mut Request, err: Box<Error + Send>,
-                         handler: &Box<Handler>) -> (Response, Option<Box<Error + Send>>) {
+                         handler: &Box<Handler>) -> AfterResult {
         match handler.catch(req, err) {
-            (res, Ok(())) => (res, None),
-            (res, Err(e)) => (res, Some(e))
+            (res, Ok(())) => Ok(res),
+            (res, Err(e)) => Err((res, e))
         }
     }
 }